@@ -1,8 +1,9 @@
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -37,6 +38,7 @@ lazy_static! {
     static ref CLEANUP_NEWLINE_START: Regex = Regex::new("\\n\\s+").unwrap();
     static ref CLEANUP_NEWLINE_END: Regex = Regex::new("\\s+\\n").unwrap();
     static ref NUMERIC_ONLY_RE: Regex = Regex::new("^[0-9]+").unwrap();
+    static ref BLANK_LINE_RE: Regex = Regex::new(r"(?:\r?\n[ \t]*){2,}").unwrap();
     pub static ref NON_BREAKING_PREFIXES: HashMap<String, String> = {
         {
             let prefixes = [
@@ -81,10 +83,47 @@ pub enum PrefixType {
     NumericOnly,
 }
 
+/// Selects which sentence-boundary engine [`SentenceSplitter`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SegmentationMode {
+    /// The heuristic, Moses-derived scanner used by `split`/`split_spans`/`split_ref`.
+    #[default]
+    Heuristic,
+    /// Unicode UAX #29 sentence boundary rules, used by
+    /// [`split_unicode`](SentenceSplitter::split_unicode).
+    Unicode,
+}
+
+/// Controls how line breaks are treated before `split` scans for sentence boundaries.
+///
+/// By default (`No`), the whole input is scanned as a single block, exactly as before
+/// this option existed: a lone `\n` is just whitespace, never a sentence boundary.
+/// `Hard` and `Blank` are for text where line breaks carry structure the splitter
+/// shouldn't erase, such as Markdown lists, source comments, or email bodies.
+///
+/// Only `SegmentationMode::Heuristic` (`split`/`split_paragraph`) consults this policy.
+/// `SegmentationMode::Unicode` (`split_unicode`) always applies UAX #29's own SB3/SB4
+/// line-break rules directly to the whole input and never pre-segments on `Reflow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reflow {
+    /// Don't pre-segment on line breaks at all (current/default behavior).
+    #[default]
+    No,
+    /// Every `\n` ends a sentence, regardless of what follows it.
+    Hard,
+    /// Only blank lines (`\n\s*\n`) end a sentence; single newlines inside a paragraph
+    /// are joined with a space before scanning.
+    Blank,
+}
+
 /// Main struct for splitting text into sentences
 pub struct SentenceSplitter {
     /// Dictionary of non-breaking prefixes; keys are string prefixes, values are PrefixType enums
     non_breaking_prefixes: HashMap<String, PrefixType>,
+    /// Segmentation engine this splitter is configured to use.
+    mode: SegmentationMode,
+    /// How line breaks are treated before `split` scans for sentence boundaries.
+    reflow: Reflow,
 }
 
 fn is_closing_punctuation(c: char) -> bool {
@@ -106,104 +145,330 @@ fn is_closing_punctuation(c: char) -> bool {
     )
 }
 
-fn is_sentence_starter(c: char) -> bool {
-    c.is_uppercase()
-        || c == '"'
-        || c == '('
-        || c.is_numeric()
-        || c == '«'  // Add guillemet
-        || c == '¿'  // Spanish/Portuguese question mark
-        || c == '¡'  // Spanish/Portuguese exclamation mark
-        || c == '"'  // Smart quote
-        || c == 0x27 as char // Smart quote
-        || c == '‹'  // Single guillemet
-        || c == '「' // CJK quote
-        || c == '『' // CJK quote
+/// Coarse approximation of the Unicode sentence-break property categories used by
+/// [`SentenceSplitter::split_unicode`] (UAX #29). Combining marks get their own category
+/// (`ExtendFormat`) so boundary rules can ignore them when checking adjacent characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SentenceBreakClass {
+    Cr,
+    Lf,
+    Sep,
+    Sp,
+    Lower,
+    Upper,
+    OLetter,
+    Numeric,
+    ATerm,
+    STerm,
+    Close,
+    SContinue,
+    ExtendFormat,
+    Other,
 }
 
-impl SentenceSplitter {
-    /// Create a new SentenceSplitter instance
-    ///
-    /// # Arguments
-    /// * `language` - ISO 639-1 language code
-    /// * `non_breaking_prefix_file` - Optional path to non-breaking prefix file
-    pub fn new<P: AsRef<Path>>(
-        language: &str,
-        non_breaking_prefix_file: Option<P>,
-    ) -> Result<Self> {
+fn is_extend_format(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036F}' | '\u{200D}' | '\u{FEFF}' | '\u{1AB0}'..='\u{1AFF}')
+}
+
+fn classify_sentence_break(c: char) -> SentenceBreakClass {
+    use SentenceBreakClass::*;
+    if c == '\r' {
+        Cr
+    } else if c == '\n' {
+        Lf
+    } else if matches!(c, '\u{0085}' | '\u{2028}' | '\u{2029}') {
+        Sep
+    } else if is_extend_format(c) {
+        ExtendFormat
+    } else if c.is_whitespace() {
+        Sp
+    } else if c == '.' {
+        ATerm
+    } else if c == '!' || c == '?' {
+        STerm
+    } else if is_closing_punctuation(c) {
+        Close
+    } else if c == ',' {
+        SContinue
+    } else if c.is_lowercase() {
+        Lower
+    } else if c.is_uppercase() {
+        Upper
+    } else if c.is_alphabetic() {
+        OLetter
+    } else if c.is_numeric() {
+        Numeric
+    } else {
+        Other
+    }
+}
+
+/// Nearest following sentence-break class from `from` onward, skipping over
+/// `Extend`/`Format` characters (UAX #29 SB5).
+fn next_effective_class(classes: &[SentenceBreakClass], from: usize) -> Option<SentenceBreakClass> {
+    let mut i = from;
+    while i < classes.len() {
+        if classes[i] != SentenceBreakClass::ExtendFormat {
+            return Some(classes[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Like [`next_effective_class`], but scans backward from `from` (inclusive), skipping any
+/// `ExtendFormat` characters per SB5. Used to find the class immediately preceding an
+/// `ATerm`/`STerm` for SB7's "preceded by Upper/Lower" qualifier.
+fn prev_effective_class(classes: &[SentenceBreakClass], from: usize) -> Option<SentenceBreakClass> {
+    let mut i = from;
+    loop {
+        if classes[i] != SentenceBreakClass::ExtendFormat {
+            return Some(classes[i]);
+        }
+        if i == 0 {
+            return None;
+        }
+        i -= 1;
+    }
+}
+
+/// Parse a non-breaking prefix list (the same format used by the bundled
+/// `non_breaking_prefixes/*.txt` files) and merge its entries into `into`, overwriting any
+/// existing entries with the same prefix.
+fn parse_non_breaking_prefixes(
+    contents: &str,
+    into: &mut HashMap<String, PrefixType>,
+) -> Result<()> {
+    let reader = BufReader::new(contents.as_bytes());
+    for line in reader.lines() {
+        let line = line?;
+
+        // Skip empty lines and comments
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let prefix_type = if line.contains("#NUMERIC_ONLY#") {
+            PrefixType::NumericOnly
+        } else {
+            PrefixType::Default
+        };
+
+        // Remove comments and clean up the line
+        let clean_line = line.split('#').next().unwrap_or("").trim().to_string();
+
+        if !clean_line.is_empty() {
+            into.insert(clean_line, prefix_type);
+        }
+    }
+
+    Ok(())
+}
+
+/// Builder for [`SentenceSplitter`], for callers who need to add domain-specific
+/// abbreviations ("Fig.", "Eq.", ticker symbols) on top of or instead of the bundled
+/// language defaults.
+///
+/// Obtained via [`SentenceSplitter::builder`].
+pub struct SentenceSplitterBuilder {
+    language: String,
+    merge_defaults: bool,
+    prefix_files: Vec<PathBuf>,
+    extra_prefixes: HashMap<String, PrefixType>,
+    mode: SegmentationMode,
+    reflow: Reflow,
+}
+
+impl SentenceSplitterBuilder {
+    fn new(language: &str) -> Self {
+        SentenceSplitterBuilder {
+            language: language.to_string(),
+            merge_defaults: true,
+            prefix_files: Vec::new(),
+            extra_prefixes: HashMap::new(),
+            mode: SegmentationMode::default(),
+            reflow: Reflow::default(),
+        }
+    }
+
+    /// Select the segmentation engine the built splitter defaults to.
+    pub fn mode(mut self, mode: SegmentationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Select how `split` treats line breaks. See [`Reflow`].
+    pub fn reflow(mut self, reflow: Reflow) -> Self {
+        self.reflow = reflow;
+        self
+    }
+
+    /// Add a single non-breaking prefix, overriding any bundled or file-provided entry
+    /// for the same word.
+    pub fn add_prefix(mut self, word: impl Into<String>, prefix_type: PrefixType) -> Self {
+        self.extra_prefixes.insert(word.into(), prefix_type);
+        self
+    }
+
+    /// Add a non-breaking prefix file to merge in, in the same format as the bundled
+    /// `non_breaking_prefixes/*.txt` lists. Can be called more than once; later files take
+    /// precedence over earlier ones, and `add_prefix` entries over every file.
+    pub fn add_prefix_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.prefix_files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Whether to load the bundled language defaults before merging in prefix files and
+    /// `add_prefix` entries. Defaults to `true`; pass `false` to use only the prefixes
+    /// supplied explicitly.
+    pub fn merge_defaults(mut self, merge_defaults: bool) -> Self {
+        self.merge_defaults = merge_defaults;
+        self
+    }
+
+    /// Build the [`SentenceSplitter`], validating the language code and loading/merging
+    /// all configured prefix sources.
+    pub fn build(self) -> Result<SentenceSplitter> {
         // Validate language code
         let lang_regex = Regex::new(r"^[a-z][a-z]$").unwrap();
-        if !lang_regex.is_match(language) {
-            return Err(SentenceSplitterError::InvalidLanguageCode(
-                language.to_string(),
-            ));
+        if !lang_regex.is_match(&self.language) {
+            return Err(SentenceSplitterError::InvalidLanguageCode(self.language));
         }
 
         let mut non_breaking_prefixes: HashMap<String, PrefixType> = HashMap::new();
-        let non_breaking_prefixes_file_contents = NON_BREAKING_PREFIXES
-            .get(language)
-            .cloned()
-            .unwrap_or_default();
-
-        // Create a reader for the non-breaking prefixes file contents
-        let reader = BufReader::new(non_breaking_prefixes_file_contents.as_bytes());
-        for line in reader.lines() {
-            let line = line?;
-
-            // Skip empty lines and comments
-            if line.trim().is_empty() || line.trim_start().starts_with('#') {
-                continue;
-            }
-
-            let prefix_type = if line.contains("#NUMERIC_ONLY#") {
-                PrefixType::NumericOnly
-            } else {
-                PrefixType::Default
-            };
 
-            // Remove comments and clean up the line
-            let clean_line = line.split('#').next().unwrap_or("").trim().to_string();
+        if self.merge_defaults {
+            let defaults = NON_BREAKING_PREFIXES
+                .get(&self.language)
+                .cloned()
+                .unwrap_or_default();
+            parse_non_breaking_prefixes(&defaults, &mut non_breaking_prefixes)?;
+        }
 
-            if !clean_line.is_empty() {
-                non_breaking_prefixes.insert(clean_line, prefix_type);
+        for path in &self.prefix_files {
+            if !path.exists() {
+                return Err(SentenceSplitterError::PrefixFileNotFound(
+                    path.display().to_string(),
+                ));
             }
+            let contents = std::fs::read_to_string(path)?;
+            parse_non_breaking_prefixes(&contents, &mut non_breaking_prefixes)?;
         }
 
+        non_breaking_prefixes.extend(self.extra_prefixes);
+
         Ok(SentenceSplitter {
             non_breaking_prefixes,
+            mode: self.mode,
+            reflow: self.reflow,
         })
     }
+}
+
+impl SentenceSplitter {
+    /// Create a new SentenceSplitter instance
+    ///
+    /// # Arguments
+    /// * `language` - ISO 639-1 language code
+    /// * `non_breaking_prefix_file` - Optional path to non-breaking prefix file, merged on
+    ///   top of the bundled language defaults
+    pub fn new<P: AsRef<Path>>(
+        language: &str,
+        non_breaking_prefix_file: Option<P>,
+    ) -> Result<Self> {
+        let mut builder = Self::builder(language);
+        if let Some(path) = non_breaking_prefix_file {
+            builder = builder.add_prefix_file(path);
+        }
+        builder.build()
+    }
+
+    /// Start building a [`SentenceSplitter`] with custom non-breaking prefixes.
+    ///
+    /// # Arguments
+    /// * `language` - ISO 639-1 language code
+    pub fn builder(language: &str) -> SentenceSplitterBuilder {
+        SentenceSplitterBuilder::new(language)
+    }
 
     /// Split text into sentences
     ///
     /// # Arguments
     /// * `text` - Text to be split into individual sentences
-    /// Split text into sentences
+    ///
+    /// Line breaks are handled according to the splitter's configured [`Reflow`] policy
+    /// before scanning: each resulting paragraph is split independently and the sentences
+    /// are concatenated in order.
     pub fn split(&self, text: &str) -> Vec<String> {
         if text.is_empty() {
             return vec![];
         }
 
+        Self::split_into_paragraphs(text, self.reflow)
+            .iter()
+            .flat_map(|paragraph| self.split_paragraph(paragraph))
+            .collect()
+    }
+
+    /// Pre-segment `text` into paragraphs according to `reflow`, before the per-character
+    /// sentence scan runs on each one.
+    fn split_into_paragraphs(text: &str, reflow: Reflow) -> Vec<String> {
+        match reflow {
+            Reflow::No => vec![text.to_string()],
+            Reflow::Hard => text.lines().map(|line| line.to_string()).collect(),
+            Reflow::Blank => BLANK_LINE_RE
+                .split(text)
+                .map(|paragraph| paragraph.replace('\n', " "))
+                .collect(),
+        }
+    }
+
+    /// Run the heuristic, Moses-derived sentence scan over a single paragraph (no
+    /// newlines are treated as boundaries here; that's handled by `split_into_paragraphs`).
+    fn split_paragraph(&self, text: &str) -> Vec<String> {
+        if text.is_empty() {
+            return vec![];
+        }
+
         // Normalize spaces first
         let text = CLEANUP_SPACES.replace_all(text, " ");
         let text = text.trim();
 
-        let mut sentences: Vec<String> = Vec::new();
-        let mut current = String::new();
+        // Delegate to `split_spans` so the two can never disagree on where a boundary
+        // falls: this is the same scan, just additionally reporting ranges.
+        self.split_spans(text)
+            .into_iter()
+            .map(|range| text[range].to_string())
+            .collect()
+    }
+
+    /// Split text into sentences, returning byte-offset ranges into `text` instead of
+    /// owned strings.
+    ///
+    /// Unlike [`split`](Self::split), this does not collapse whitespace before scanning,
+    /// so every returned range indexes directly into the caller's original input and can
+    /// be used to re-slice it or to map a sentence back to a position in the source
+    /// document. Leading/trailing whitespace around a sentence is excluded from its range
+    /// rather than being trimmed into a new allocation.
+    pub fn split_spans(&self, text: &str) -> Vec<Range<usize>> {
+        if text.is_empty() {
+            return vec![];
+        }
 
-        let mut chars: Vec<char> = text.chars().collect();
-        chars.push(' '); // Add trailing space for simpler processing
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let len = text.len();
 
-        let mut i = 0;
+        let mut spans: Vec<Range<usize>> = Vec::new();
+        let mut current_start = 0usize;
         let mut in_quotes = false;
         let mut quote_char = ' ';
 
-        while i < chars.len() - 1 {
-            let c = chars[i];
-            current.push(c);
+        let mut i = 0;
+        while i < chars.len() {
+            let (byte_idx, c) = chars[i];
 
             // Track quote status
-            if c == '"' || c == '"' || c == '"' {
+            if c == '"' {
                 if !in_quotes {
                     in_quotes = true;
                     quote_char = c;
@@ -213,30 +478,29 @@ impl SentenceSplitter {
             }
 
             if c == '.' || c == '?' || c == '!' {
+                let end_idx = byte_idx + c.len_utf8();
+
                 // Look ahead for sentence boundary
-                let mut next_char_idx = i + 1;
-                while next_char_idx < chars.len() && chars[next_char_idx].is_whitespace() {
-                    next_char_idx += 1;
+                let mut next_idx = i + 1;
+                while next_idx < chars.len() && chars[next_idx].1.is_whitespace() {
+                    next_idx += 1;
                 }
 
-                // Get the current fragment for analysis
-                let current_fragment = current.as_str();
-
                 // Check for acronyms first
-                if ACRONYM_RE.is_match(current_fragment) {
+                if ACRONYM_RE.is_match(&text[current_start..end_idx]) {
                     i += 1;
                     continue;
                 }
 
                 let mut should_split = false;
-                if next_char_idx < chars.len() {
-                    let next_char = chars[next_char_idx];
+                if next_idx < chars.len() {
+                    let next_char = chars[next_idx].1;
 
                     // Handle various splitting conditions
                     should_split = if in_quotes {
                         // Only split on quote end
                         false
-                    } else if i > 0 && chars[i - 1] == ')' {
+                    } else if i > 0 && chars[i - 1].1 == ')' {
                         // Handle parenthetical endings
                         true
                     } else {
@@ -250,42 +514,376 @@ impl SentenceSplitter {
 
                     // Check for non-breaking prefixes
                     if should_split {
-                        let current_word = current.split_whitespace().last().unwrap_or("");
-                        let word_without_dot =
-                            current_word.trim_end_matches(|c| c == '.' || c == '!' || c == '?');
-
-                        if self.non_breaking_prefixes.contains_key(word_without_dot) {
-                            match self.non_breaking_prefixes.get(word_without_dot).unwrap() {
-                                PrefixType::NumericOnly => {
-                                    should_split = !chars[next_char_idx].is_numeric();
-                                }
-                                PrefixType::Default => {
-                                    should_split = false;
-                                }
-                            }
+                        let current_word = text[current_start..end_idx]
+                            .split_whitespace()
+                            .last()
+                            .unwrap_or("");
+                        let word_without_dot = current_word.trim_end_matches(['.', '!', '?']);
+
+                        if let Some(prefix_type) = self.non_breaking_prefixes.get(word_without_dot)
+                        {
+                            should_split = match prefix_type {
+                                PrefixType::NumericOnly => !next_char.is_numeric(),
+                                PrefixType::Default => false,
+                            };
                         }
                     }
                 }
 
                 if should_split {
-                    sentences.push(current.trim().to_string());
-                    current.clear();
-                    i = next_char_idx - 1;
+                    spans.push(trim_range(text, current_start..end_idx));
+                    current_start = chars.get(next_idx).map_or(len, |(idx, _)| *idx);
+                    i = next_idx;
+                    continue;
                 }
             }
             i += 1;
         }
 
         // Add final sentence if there's content
-        if !current.is_empty() {
-            sentences.push(current.trim().to_string());
+        if current_start < len {
+            spans.push(trim_range(text, current_start..len));
         }
 
         // Clean up any empty sentences
-        sentences.into_iter().filter(|s| !s.is_empty()).collect()
+        spans.into_iter().filter(|r| !r.is_empty()).collect()
+    }
+
+    /// Split text into sentences, borrowing each sentence from the original input rather
+    /// than allocating a new `String` per sentence.
+    ///
+    /// See [`split_spans`](Self::split_spans) for how boundaries are computed.
+    pub fn split_ref<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        self.split_spans(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    /// Split text into sentences using the Unicode UAX #29 sentence boundary rules,
+    /// rather than the heuristic Moses-derived scanner `split` uses.
+    ///
+    /// This is independent of the splitter's configured non-breaking prefixes: UAX #29
+    /// boundaries are determined purely from the sentence-break property of each
+    /// character, which makes this mode more reliable for CJK text and mixed scripts
+    /// where the "next letter is uppercase" heuristic doesn't apply.
+    pub fn split_unicode(&self, text: &str) -> Vec<String> {
+        if text.is_empty() {
+            return vec![];
+        }
+
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let classes: Vec<SentenceBreakClass> =
+            chars.iter().map(|&(_, c)| classify_sentence_break(c)).collect();
+        let len = chars.len();
+        let byte_end_of = |idx: usize| -> usize {
+            chars.get(idx + 1).map_or(text.len(), |&(b, _)| b)
+        };
+
+        let mut sentences = Vec::new();
+        let mut seg_start = 0usize;
+        let mut i = 0usize;
+
+        while i < len {
+            match classes[i] {
+                // SB3: never break inside CRLF; let the LF drive the break instead.
+                SentenceBreakClass::Cr if matches!(classes.get(i + 1), Some(SentenceBreakClass::Lf)) => {
+                    i += 1;
+                }
+                // SB4: always break after Sep/CR/LF.
+                SentenceBreakClass::Cr | SentenceBreakClass::Lf | SentenceBreakClass::Sep => {
+                    let end = byte_end_of(i);
+                    sentences.push(text[seg_start..end].to_string());
+                    seg_start = end;
+                    i += 1;
+                }
+                SentenceBreakClass::ATerm | SentenceBreakClass::STerm => {
+                    // SB5/SB8a: no break before a directly-following SContinue/ATerm/STerm
+                    // (ignoring any Extend/Format in between).
+                    if matches!(
+                        next_effective_class(&classes, i + 1),
+                        Some(SentenceBreakClass::SContinue)
+                            | Some(SentenceBreakClass::ATerm)
+                            | Some(SentenceBreakClass::STerm)
+                    ) {
+                        i += 1;
+                        continue;
+                    }
+
+                    // SB9/SB10: skip any run of Close*, then Sp*, without considering a
+                    // break inside that run (also ignoring any Extend/Format, per SB5).
+                    let mut j = i + 1;
+                    while j < len
+                        && matches!(
+                            classes[j],
+                            SentenceBreakClass::Close | SentenceBreakClass::ExtendFormat
+                        )
+                    {
+                        j += 1;
+                    }
+                    while j < len
+                        && matches!(
+                            classes[j],
+                            SentenceBreakClass::Sp | SentenceBreakClass::ExtendFormat
+                        )
+                    {
+                        j += 1;
+                    }
+
+                    let next_class = classes.get(j).copied();
+
+                    // SB6: don't break before a Numeric that directly continues a decimal
+                    // literal, with nothing between the ATerm and the digit (e.g. "3.14").
+                    let decimal_continuation = matches!(
+                        classes.get(i + 1),
+                        Some(SentenceBreakClass::Numeric)
+                    );
+
+                    // SB7: don't break before a Numeric preceded by a single Upper/Lower
+                    // initial (e.g. "J. 5"), as opposed to the last letter of an ordinary
+                    // word (e.g. "All done. 5 more to go." still breaks).
+                    let preceded_by_initial = i > 0
+                        && matches!(
+                            prev_effective_class(&classes, i - 1),
+                            Some(SentenceBreakClass::Upper) | Some(SentenceBreakClass::Lower)
+                        )
+                        && !(i > 1
+                            && matches!(
+                                prev_effective_class(&classes, i - 2),
+                                Some(SentenceBreakClass::Upper)
+                                    | Some(SentenceBreakClass::Lower)
+                                    | Some(SentenceBreakClass::OLetter)
+                            ));
+
+                    // SB8: don't break before a lowercase continuation (e.g. "etc. however").
+                    let no_break = matches!(next_class, Some(SentenceBreakClass::Lower))
+                        || decimal_continuation
+                        || (preceded_by_initial
+                            && matches!(next_class, Some(SentenceBreakClass::Numeric)));
+
+                    if no_break {
+                        i += 1;
+                    } else {
+                        // SB11: break after ATerm/STerm Close* Sp*.
+                        let end = if j < len { chars[j].0 } else { text.len() };
+                        sentences.push(text[seg_start..end].to_string());
+                        seg_start = end;
+                        i = j;
+                    }
+                }
+                // SB12: otherwise, do not break.
+                _ => i += 1,
+            }
+        }
+
+        if seg_start < text.len() {
+            sentences.push(text[seg_start..].to_string());
+        }
+
+        sentences
+            .into_iter()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    /// Split text into sentences using whichever [`SegmentationMode`] the splitter was
+    /// built with.
+    ///
+    /// Note that [`Reflow`] is a `Heuristic`-only setting: in `Unicode` mode this is
+    /// exactly `split_unicode(text)`, regardless of how `self.reflow` is configured.
+    pub fn split_auto(&self, text: &str) -> Vec<String> {
+        match self.mode {
+            SegmentationMode::Heuristic => self.split(text),
+            SegmentationMode::Unicode => self.split_unicode(text),
+        }
+    }
+
+    /// Split sentences out of a [`BufRead`] incrementally, without loading the whole
+    /// input into memory up front.
+    ///
+    /// Honors the splitter's configured [`Reflow`] and [`SegmentationMode`]. With the
+    /// default `Heuristic` mode and `Reflow::No`, a sentence is only yielded once
+    /// `split_spans` has scanned past its following sentence starter and resolved any
+    /// non-breaking-prefix lookahead against what's been read so far, so the decision
+    /// never has to be revisited as more input arrives. In `Heuristic` mode with
+    /// `Reflow::Hard`/`Reflow::Blank`, each line/paragraph is instead buffered up to its
+    /// line-break boundary and run through the same per-paragraph scan `split`/`split_auto`
+    /// uses, since those policies make line breaks significant and a decision can't be made
+    /// without knowing where the next one falls. `SegmentationMode::Unicode` has no
+    /// incremental span API, so it falls back to buffering the entire input before yielding
+    /// anything, whatever the `Reflow` setting.
+    ///
+    /// Memory use is bounded by the text read since the last yielded sentence (`Reflow::No`),
+    /// the current line (`Reflow::Hard`) or the current paragraph (`Reflow::Blank`), and in
+    /// `Unicode` mode by the whole input: a pathological input with one multi-gigabyte
+    /// line and no embedded `\n` is still read into `buffer` in full before any sentence
+    /// can be yielded from it.
+    pub fn split_reader<R: BufRead>(&self, reader: R) -> SentenceReader<'_, R> {
+        SentenceReader {
+            splitter: self,
+            reader,
+            buffer: String::new(),
+            pending: VecDeque::new(),
+            eof: false,
+        }
     }
 }
 
+/// Iterator returned by [`SentenceSplitter::split_reader`].
+pub struct SentenceReader<'a, R: BufRead> {
+    splitter: &'a SentenceSplitter,
+    reader: R,
+    buffer: String,
+    pending: VecDeque<String>,
+    eof: bool,
+}
+
+impl<'a, R: BufRead> SentenceReader<'a, R> {
+    /// Pull one more line from `reader` into `buffer`. Returns `false` at EOF.
+    fn fill(&mut self) -> io::Result<bool> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => {
+                self.eof = true;
+                Ok(false)
+            }
+            Ok(_) => {
+                self.buffer.push_str(&line);
+                Ok(true)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// `Reflow::No`: scan the raw buffer directly, the same way `split_spans` always has.
+    fn next_no_reflow(&mut self) -> Option<io::Result<String>> {
+        loop {
+            let spans = self.splitter.split_spans(&self.buffer);
+
+            // More than one span means the boundary after the first is final: the scan
+            // has already resolved whatever comes after it. At EOF, a single remaining
+            // span is final by definition since no more input can change it.
+            if spans.len() > 1 || (self.eof && spans.len() == 1) {
+                let first = spans[0].clone();
+                let sentence = CLEANUP_SPACES
+                    .replace_all(&self.buffer[first], " ")
+                    .trim()
+                    .to_string();
+                let next_start = spans.get(1).map_or(self.buffer.len(), |r| r.start);
+                self.buffer.drain(..next_start);
+                return Some(Ok(sentence));
+            }
+
+            if self.eof {
+                return None;
+            }
+
+            if let Err(e) = self.fill() {
+                return Some(Err(e));
+            }
+        }
+    }
+
+    /// `Reflow::Hard`: every `\n` ends the current paragraph regardless of what follows,
+    /// so each line can be split independently as soon as it's fully read.
+    fn next_hard_reflow(&mut self) -> Option<io::Result<String>> {
+        loop {
+            if let Some(end) = self.buffer.find('\n') {
+                let line: String = self.buffer.drain(..=end).collect();
+                let line = line.trim_end_matches(['\n', '\r']);
+                self.pending.extend(self.splitter.split_auto(line));
+            } else if self.eof {
+                if !self.buffer.is_empty() {
+                    let paragraph = std::mem::take(&mut self.buffer);
+                    self.pending.extend(self.splitter.split_auto(&paragraph));
+                }
+                return self.pending.pop_front().map(Ok);
+            } else if let Err(e) = self.fill() {
+                return Some(Err(e));
+            }
+
+            if let Some(sentence) = self.pending.pop_front() {
+                return Some(Ok(sentence));
+            }
+        }
+    }
+
+    /// `Reflow::Blank`: only a blank line ends the current paragraph; single newlines
+    /// inside one are joined with a space before scanning, matching `split_into_paragraphs`.
+    fn next_blank_reflow(&mut self) -> Option<io::Result<String>> {
+        loop {
+            if let Some(m) = BLANK_LINE_RE.find(&self.buffer) {
+                let (start, end) = (m.start(), m.end());
+                let paragraph = self.buffer[..start].replace('\n', " ");
+                self.buffer.drain(..end);
+                self.pending.extend(self.splitter.split_auto(&paragraph));
+            } else if self.eof {
+                if !self.buffer.is_empty() {
+                    let paragraph = std::mem::take(&mut self.buffer).replace('\n', " ");
+                    self.pending.extend(self.splitter.split_auto(&paragraph));
+                }
+                return self.pending.pop_front().map(Ok);
+            } else if let Err(e) = self.fill() {
+                return Some(Err(e));
+            }
+
+            if let Some(sentence) = self.pending.pop_front() {
+                return Some(Ok(sentence));
+            }
+        }
+    }
+
+    /// `SegmentationMode::Unicode` has no incremental span API like `split_spans`, and
+    /// `split_unicode`/`split_auto` never consult `self.reflow` at all (`Reflow` is a
+    /// `Heuristic`-only setting), so there's no paragraph boundary to chunk on and no way
+    /// to know a sentence boundary is final without seeing the rest of the input; fall
+    /// back to buffering everything and splitting once at EOF, regardless of `Reflow`.
+    fn next_unicode_buffered(&mut self) -> Option<io::Result<String>> {
+        loop {
+            if self.eof {
+                if !self.buffer.is_empty() {
+                    let text = std::mem::take(&mut self.buffer);
+                    self.pending.extend(self.splitter.split_unicode(&text));
+                }
+                return self.pending.pop_front().map(Ok);
+            }
+            if let Err(e) = self.fill() {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+impl<'a, R: BufRead> Iterator for SentenceReader<'a, R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(sentence) = self.pending.pop_front() {
+            return Some(Ok(sentence));
+        }
+
+        if self.splitter.mode == SegmentationMode::Unicode {
+            return self.next_unicode_buffered();
+        }
+
+        match self.splitter.reflow {
+            Reflow::No => self.next_no_reflow(),
+            Reflow::Hard => self.next_hard_reflow(),
+            Reflow::Blank => self.next_blank_reflow(),
+        }
+    }
+}
+
+/// Trim leading/trailing whitespace from a byte range by shrinking its bounds, without
+/// allocating a new string.
+fn trim_range(text: &str, range: Range<usize>) -> Range<usize> {
+    let slice = &text[range.start..range.end];
+    let start = range.start + slice.len() - slice.trim_start().len();
+    let end = range.start + slice.trim_end().len();
+    start..end.max(start)
+}
+
 /// Split text into sentences (convenience function)
 ///
 /// For better performance, use SentenceSplitter struct directly to avoid reloading
@@ -448,20 +1046,295 @@ mod tests {
         assert_eq!(splitter.split(input_text), expected_sentences);
     }
 
-    // #[test]
-    // fn test_custom_non_breaking_prefixes() {
-    //     let mut temp_file = NamedTempFile::new().unwrap();
-    //     writeln!(
-    //         temp_file,
-    //         "# \n# Temporary prefix file\n# \n\nPrefix1\nPrefix2\n"
-    //     )
-    //     .unwrap();
-
-    //     let splitter = SentenceSplitter::new("xx", Some(temp_file.path())).unwrap();
-    //     let input_text = "Hello. Prefix1. Prefix2. Hello again. Good bye.";
-    //     let expected_sentences = vec!["Hello.", "Prefix1. Prefix2. Hello again.", "Good bye."];
-    //     assert_eq!(splitter.split(input_text), expected_sentences);
-    // }
+    #[test]
+    fn test_custom_non_breaking_prefixes() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            "# \n# Temporary prefix file\n# \n\nPrefix1\nPrefix2\n"
+        )
+        .unwrap();
+
+        let splitter = SentenceSplitter::new("xx", Some(temp_file.path())).unwrap();
+        let input_text = "Hello. Prefix1. Prefix2. Hello again. Good bye.";
+        let expected_sentences = vec!["Hello.", "Prefix1. Prefix2. Hello again.", "Good bye."];
+        assert_eq!(splitter.split(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_builder_add_prefix() {
+        let splitter = SentenceSplitter::builder("en")
+            .add_prefix("Fig", PrefixType::Default)
+            .build()
+            .unwrap();
+        let input_text = "See Fig. 2 for details. Good bye.";
+        let expected_sentences = vec!["See Fig. 2 for details.", "Good bye."];
+        assert_eq!(splitter.split(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_builder_merge_defaults_false() {
+        let splitter = SentenceSplitter::builder("en")
+            .merge_defaults(false)
+            .add_prefix("Mr", PrefixType::Default)
+            .build()
+            .unwrap();
+        let input_text = "Mr. Smith left. Good bye.";
+        let expected_sentences = vec!["Mr. Smith left.", "Good bye."];
+        assert_eq!(splitter.split(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_builder_missing_prefix_file() {
+        let result = SentenceSplitter::builder("en")
+            .add_prefix_file("/no/such/path.txt")
+            .build();
+        assert!(matches!(
+            result,
+            Err(SentenceSplitterError::PrefixFileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_split_spans_roundtrip() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let input_text =
+            "This is a paragraph.  It contains several sentences. \"But why,\" you ask?";
+
+        let spans = splitter.split_spans(input_text);
+        let from_spans: Vec<&str> = spans.iter().map(|r| &input_text[r.clone()]).collect();
+        assert_eq!(from_spans, splitter.split_ref(input_text));
+        assert_eq!(
+            from_spans,
+            vec![
+                "This is a paragraph.",
+                "It contains several sentences.",
+                "\"But why,\" you ask?",
+            ]
+        );
+
+        // Spans must index into the *original* text, whitespace and all.
+        for range in &spans {
+            assert!(range.end <= input_text.len());
+        }
+    }
+
+    #[test]
+    fn test_split_spans_matches_split_boundaries() {
+        // Under the default `Reflow::No`, `split_spans`/`split_ref` must never disagree
+        // with `split` about where a sentence boundary falls, even for characters `split`'s literal checks don't
+        // treat specially (e.g. `¿` isn't a recognized sentence starter here).
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let input_text = "He asked. ¿Qué pasa?";
+        assert_eq!(splitter.split(input_text).len(), splitter.split_ref(input_text).len());
+        assert_eq!(splitter.split(input_text), vec![input_text]);
+        assert_eq!(splitter.split_ref(input_text), vec![input_text]);
+    }
+
+    #[test]
+    fn test_split_spans_empty() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        assert_eq!(splitter.split_spans(""), Vec::<std::ops::Range<usize>>::new());
+        assert_eq!(splitter.split_ref(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_split_unicode_basic() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let input_text = "This is a paragraph. It contains several sentences.";
+        let expected_sentences = vec!["This is a paragraph.", "It contains several sentences."];
+        assert_eq!(splitter.split_unicode(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_split_unicode_no_break_on_decimal_or_initial() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        // SB6: a number like "3.14" should not be split at the decimal point.
+        let input_text = "Pi is roughly 3.14 and that's fine.";
+        assert_eq!(splitter.split_unicode(input_text), vec![input_text]);
+
+        // SB7: a single-letter initial followed by a number ("J. 5") is not a boundary.
+        let input_text = "See J. 5 for more.";
+        assert_eq!(splitter.split_unicode(input_text), vec![input_text]);
+    }
+
+    #[test]
+    fn test_split_unicode_breaks_before_sentence_starting_with_digit() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        // Unlike "3.14" (decimal) or "J. 5" (initial), an ordinary word ending a
+        // sentence (here "done") is not a decimal or initial, so the following
+        // digit-led sentence must still be split off.
+        let input_text = "All done. 5 more to go.";
+        let expected_sentences = vec!["All done.", "5 more to go."];
+        assert_eq!(splitter.split_unicode(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_split_unicode_newline_is_a_boundary() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let input_text = "First line\nSecond line";
+        let expected_sentences = vec!["First line", "Second line"];
+        assert_eq!(splitter.split_unicode(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_split_auto_dispatches_on_mode() {
+        let heuristic = SentenceSplitter::builder("en").build().unwrap();
+        let unicode = SentenceSplitter::builder("en")
+            .mode(SegmentationMode::Unicode)
+            .build()
+            .unwrap();
+
+        let input_text = "First line\nSecond line";
+        assert_eq!(heuristic.split_auto(input_text), heuristic.split(input_text));
+        assert_eq!(
+            unicode.split_auto(input_text),
+            unicode.split_unicode(input_text)
+        );
+        assert_ne!(heuristic.split_auto(input_text), unicode.split_auto(input_text));
+    }
+
+    #[test]
+    fn test_reflow_no_is_unaffected_by_single_newlines() {
+        let splitter = SentenceSplitter::builder("en").build().unwrap();
+        // `Reflow::No` (the default) is a pre-segmentation no-op: a lone `\n` was never
+        // a sentence boundary and still isn't, matching `split`'s prior behavior.
+        let input_text = "Apples\nBananas\nCherries.";
+        assert_eq!(splitter.split(input_text), vec![input_text]);
+    }
+
+    #[test]
+    fn test_reflow_hard_breaks_on_every_newline() {
+        let splitter = SentenceSplitter::builder("en")
+            .reflow(Reflow::Hard)
+            .build()
+            .unwrap();
+        let input_text = "Apples\nBananas\nCherries.";
+        let expected_sentences = vec!["Apples", "Bananas", "Cherries."];
+        assert_eq!(splitter.split(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_reflow_blank_joins_single_newlines() {
+        let splitter = SentenceSplitter::builder("en")
+            .reflow(Reflow::Blank)
+            .build()
+            .unwrap();
+        let input_text = "This paragraph\nwraps across two lines.\n\nThis is a new paragraph.";
+        let expected_sentences = vec![
+            "This paragraph wraps across two lines.",
+            "This is a new paragraph.",
+        ];
+        assert_eq!(splitter.split(input_text), expected_sentences);
+    }
+
+    #[test]
+    fn test_split_reader_basic() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let input_text =
+            "This is a paragraph. It contains several sentences. \"But why,\" you ask?";
+        let cursor = std::io::Cursor::new(input_text.as_bytes());
+        let sentences: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            sentences,
+            vec![
+                "This is a paragraph.",
+                "It contains several sentences.",
+                "\"But why,\" you ask?",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_reader_matches_split() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let input_text = "Hello. No. 1. No. 2. Prefix. 1. Prefix. 2. Good bye.";
+        let cursor = std::io::Cursor::new(input_text.as_bytes());
+        let streamed: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(streamed, splitter.split(input_text));
+    }
+
+    #[test]
+    fn test_split_reader_matches_split_with_hard_reflow() {
+        let splitter = SentenceSplitter::builder("en")
+            .reflow(Reflow::Hard)
+            .build()
+            .unwrap();
+        let input_text = "Apples\nBananas\nCherries.";
+        let cursor = std::io::Cursor::new(input_text.as_bytes());
+        let streamed: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(streamed, splitter.split(input_text));
+        assert_eq!(streamed, vec!["Apples", "Bananas", "Cherries."]);
+    }
+
+    #[test]
+    fn test_split_reader_matches_split_with_blank_reflow() {
+        let splitter = SentenceSplitter::builder("en")
+            .reflow(Reflow::Blank)
+            .build()
+            .unwrap();
+        let input_text = "Line one\nline two.\n\nSecond paragraph.";
+        let cursor = std::io::Cursor::new(input_text.as_bytes());
+        let streamed: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(streamed, splitter.split(input_text));
+    }
+
+    #[test]
+    fn test_split_reader_matches_split_unicode_with_no_reflow() {
+        let splitter = SentenceSplitter::builder("en")
+            .mode(SegmentationMode::Unicode)
+            .build()
+            .unwrap();
+        let input_text = "All done. 5 more to go.";
+        let cursor = std::io::Cursor::new(input_text.as_bytes());
+        let streamed: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(streamed, splitter.split_auto(input_text));
+    }
+
+    #[test]
+    fn test_split_reader_matches_split_auto_unicode_with_blank_reflow() {
+        // `Reflow` only governs `Heuristic` mode; `split_auto`/`split_unicode` ignore it
+        // entirely even when set to `Blank`, so `split_reader` must match that rather
+        // than pre-joining single newlines the way it does for `Heuristic` + `Blank`.
+        let splitter = SentenceSplitter::builder("en")
+            .mode(SegmentationMode::Unicode)
+            .reflow(Reflow::Blank)
+            .build()
+            .unwrap();
+        let input_text = "Line one\nline two.\n\nSecond paragraph.";
+        let cursor = std::io::Cursor::new(input_text.as_bytes());
+        let streamed: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(streamed, splitter.split_auto(input_text));
+    }
+
+    #[test]
+    fn test_split_reader_empty() {
+        let splitter = SentenceSplitter::new("en", None::<PathBuf>).unwrap();
+        let cursor = std::io::Cursor::new(b"".as_slice());
+        let sentences: Vec<String> = splitter
+            .split_reader(cursor)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert!(sentences.is_empty());
+    }
 
     #[test]
     fn test_split_text_into_sentences() {